@@ -0,0 +1,303 @@
+use std::{
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+};
+
+use crate::{raw, CircularBuffer};
+
+/// A circular buffer whose capacity is chosen at runtime, backed by a heap allocation.
+///
+/// This mirrors [`CircBuf`](crate::CircBuf), but trades the const-generic, allocation-free
+/// storage for a `Box<[MaybeUninit<T>]>` sized by [`CircBufVec::with_capacity`].
+///
+/// # Example
+///
+/// ```
+/// use circbuf::CircBufVec;
+///
+/// let mut buf = CircBufVec::<i32>::with_capacity(16);
+/// for i in 0..16 {
+///     buf.push(i);
+/// }
+/// assert!(buf.is_full());
+///
+/// for i in 16..19 {
+///     buf.push(i);
+/// }
+///
+/// println!("buf[0] = {}", buf[0]);
+/// ```
+#[derive(Debug)]
+pub struct CircBufVec<T> {
+    /// Start of the valid data in `data`.
+    start: usize,
+    /// Number of valid elements after `start`.
+    len: usize,
+    /// Storage for potential elements.
+    data: Box<[MaybeUninit<T>]>,
+}
+
+impl<T> CircBufVec<T> {
+    /// Create a new, empty circular buffer that can hold up to `capacity` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`, since a zero-capacity ring buffer has no valid index
+    /// arithmetic. `CircBuf<T, 0>` hits the same guard, in its `push`, since its capacity
+    /// is not known until it is monomorphized.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be non-zero");
+
+        let mut data = Vec::with_capacity(capacity);
+        data.resize_with(capacity, MaybeUninit::uninit);
+
+        Self {
+            start: 0,
+            len: 0,
+            data: data.into_boxed_slice(),
+        }
+    }
+
+    /// Add a new element to the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBufVec;
+    /// let mut buf = CircBufVec::with_capacity(8);
+    /// buf.push(1);
+    /// buf.push(2);
+    /// ```
+    pub fn push(&mut self, elem: T) {
+        raw::push_back(&mut self.data, &mut self.start, &mut self.len, elem);
+    }
+
+    /// Remove the oldest element from the buffer and return it if it exists.
+    /// Otherwise return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBufVec;
+    /// let mut buf = CircBufVec::with_capacity(8);
+    /// buf.push(10);
+    /// assert_eq!(buf.pop(), Some(10));
+    /// assert_eq!(buf.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        raw::pop_front(&self.data, &mut self.start, &mut self.len)
+    }
+
+    /// Get the number of values currently stored in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the maximum number of elements the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer is full and would overwrite a value on the next push.
+    pub fn is_full(&self) -> bool {
+        self.len == self.data.len()
+    }
+
+    /// Returns an iterator over the elements in the buffer.
+    pub fn iter(&self) -> VecIter<'_, T> {
+        VecIter { buf: self, idx: 0 }
+    }
+}
+
+impl<T> Drop for CircBufVec<T> {
+    fn drop(&mut self) {
+        raw::drop_valid(&mut self.data, self.start, self.len);
+    }
+}
+
+impl<T> Index<usize> for CircBufVec<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.len() {
+            panic!("the len is {} but the index is {}", self.len(), index);
+        } else {
+            let index = (self.start + index) % self.data.len();
+            // SAFETY: see `CircBuf::index`.
+            unsafe { &*self.data[index].as_ptr() }
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for CircBufVec<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.len() {
+            panic!("the len is {} but the index is {}", self.len(), index);
+        } else {
+            let size = self.data.len();
+            let index = (self.start + index) % size;
+            // SAFETY: see `CircBuf::index_mut`.
+            unsafe { &mut *self.data[index].as_mut_ptr() }
+        }
+    }
+}
+
+impl<T> CircularBuffer<T> for CircBufVec<T> {
+    fn push(&mut self, elem: T) {
+        CircBufVec::push(self, elem)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        CircBufVec::pop(self)
+    }
+
+    fn len(&self) -> usize {
+        CircBufVec::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        CircBufVec::capacity(self)
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        CircBufVec::iter(self)
+    }
+}
+
+/// Iterator over elements of a [`CircBufVec`].
+/// Created using [`CircBufVec::iter`].
+pub struct VecIter<'a, T> {
+    /// Reference to the circular buffer to iterate over.
+    buf: &'a CircBufVec<T>,
+    /// Index of the next value to return from iterator.
+    idx: usize,
+}
+
+impl<'a, T> Iterator for VecIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < self.buf.len() {
+            let elem = &self.buf[self.idx];
+            self.idx += 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.buf.len(), Some(self.buf.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CircBuf;
+
+    #[test]
+    fn test_empty_pop() {
+        let mut buf: CircBufVec<i32> = CircBufVec::with_capacity(8);
+        assert!(buf.is_empty());
+        assert_eq!(buf.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be non-zero")]
+    fn test_with_capacity_zero_panics() {
+        let _buf: CircBufVec<i32> = CircBufVec::with_capacity(0);
+    }
+
+    #[test]
+    fn test_wrapping_push_matches_circ_buf() {
+        let mut vec_buf = CircBufVec::with_capacity(5);
+        let mut arr_buf: CircBuf<_, 5> = CircBuf::new();
+        for i in 0..8 {
+            vec_buf.push(i);
+            arr_buf.push(i);
+        }
+        assert_eq!(
+            vec_buf.iter().copied().collect::<Vec<i32>>(),
+            arr_buf.iter().copied().collect::<Vec<i32>>()
+        );
+    }
+
+    #[test]
+    fn test_wrapping_pop_matches_circ_buf() {
+        let mut vec_buf = CircBufVec::with_capacity(5);
+        let mut arr_buf: CircBuf<_, 5> = CircBuf::new();
+        for i in 0..6 {
+            vec_buf.push(i);
+            arr_buf.push(i);
+        }
+        assert_eq!(vec_buf.pop(), arr_buf.pop());
+        assert_eq!(vec_buf.len(), arr_buf.len());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut buf = CircBufVec::with_capacity(8);
+        for i in 0..6 {
+            buf.push(i);
+        }
+        buf.pop();
+
+        assert_eq!(
+            buf.iter().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_drop_on_wrapping_overwrite() {
+        struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let count = std::cell::Cell::new(0);
+        {
+            let mut buf = CircBufVec::with_capacity(4);
+            for _ in 0..6 {
+                buf.push(DropCounter(&count));
+            }
+            assert_eq!(count.get(), 2);
+        }
+        assert_eq!(count.get(), 6);
+    }
+
+    #[test]
+    fn test_generic_over_circular_buffer() {
+        fn fill<B: CircularBuffer<i32>>(buf: &mut B, count: i32) {
+            for i in 0..count {
+                buf.push(i);
+            }
+        }
+
+        let mut arr_buf: CircBuf<i32, 4> = CircBuf::new();
+        let mut vec_buf: CircBufVec<i32> = CircBufVec::with_capacity(4);
+        fill(&mut arr_buf, 6);
+        fill(&mut vec_buf, 6);
+
+        assert_eq!(
+            CircularBuffer::iter(&arr_buf)
+                .copied()
+                .collect::<Vec<i32>>(),
+            CircularBuffer::iter(&vec_buf)
+                .copied()
+                .collect::<Vec<i32>>()
+        );
+    }
+}
@@ -1,8 +1,16 @@
 use std::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
     mem::MaybeUninit,
     ops::{Index, IndexMut},
+    ptr,
 };
 
+mod circ_buf_vec;
+mod raw;
+
+pub use circ_buf_vec::{CircBufVec, VecIter};
+
 /// A circular buffer with a constant size.
 ///
 /// # Example
@@ -107,14 +115,7 @@ impl<T, const SIZE: usize> CircBuf<T, SIZE> {
     /// buf.push(2);
     /// ```
     pub fn push(&mut self, elem: T) {
-        let write_idx = (self.start + self.len) % SIZE;
-        self.data[write_idx] = MaybeUninit::new(elem);
-
-        if self.is_full() {
-            self.start = (self.start + 1) % SIZE;
-        } else {
-            self.len += 1;
-        }
+        raw::push_back(&mut self.data, &mut self.start, &mut self.len, elem);
     }
 
     /// Remove the oldest element from the buffer and return it if it exists.
@@ -130,25 +131,111 @@ impl<T, const SIZE: usize> CircBuf<T, SIZE> {
     /// assert_eq!(buf.pop(), None);
     /// ```
     pub fn pop(&mut self) -> Option<T> {
+        raw::pop_front(&self.data, &mut self.start, &mut self.len)
+    }
+
+    /// Add a new element to the front of the buffer, so it becomes the oldest element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBuf;
+    /// let mut buf: CircBuf<_, 8> = CircBuf::new();
+    /// buf.push(2);
+    /// buf.push_front(1);
+    /// assert_eq!(buf[0], 1);
+    /// assert_eq!(buf[1], 2);
+    /// ```
+    pub fn push_front(&mut self, elem: T) {
+        let write_idx = (self.start + SIZE - 1) % SIZE;
+
+        if self.is_full() {
+            // SAFETY:
+            // The buffer is full, so `write_idx` is the slot just before `start`, which
+            // holds the newest initialized element. It is about to be overwritten below,
+            // so the old value must be dropped here or it would otherwise leak.
+            unsafe { ptr::drop_in_place(self.data[write_idx].as_mut_ptr()) };
+        } else {
+            self.len += 1;
+        }
+
+        self.start = write_idx;
+        self.data[write_idx] = MaybeUninit::new(elem);
+    }
+
+    /// Remove the newest element from the buffer and return it if it exists.
+    /// Otherwise return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBuf;
+    /// let mut buf: CircBuf<_, 8> = CircBuf::new();
+    /// buf.push(1);
+    /// buf.push(2);
+    /// assert_eq!(buf.pop_back(), Some(2));
+    /// assert_eq!(buf.pop_back(), Some(1));
+    /// assert_eq!(buf.pop_back(), None);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
         if self.is_empty() {
             None
         } else {
-            // SAFETY:
-            // * Length is greater than zero so the buffer contains an initialized element *somewhere*.
-            // * Initialized values are always written in front of the `read_idx`.
-            // * `read_idx` always move forwards after an element is removed.
-            // This means `read_idx` must point to a properly initialized value and the following
-            // operation is safe.
-            //
-            // `ptr::read` does not drop the underlying value, but this is intended:
-            // Ownership of the value is transfered to the caller, and the remnents of the value
-            // in the array will be overwritten by other writes later.
-            let elem = unsafe { self.data[self.start].as_ptr().read() };
-
-            self.start = (self.start + 1) % SIZE;
+            let idx = (self.start + self.len - 1) % SIZE;
             self.len -= 1;
 
-            Some(elem)
+            // SAFETY:
+            // `idx` addresses the newest of the buffer's valid elements. Ownership of the
+            // value is transferred to the caller, and shrinking `len` keeps it from being
+            // read or dropped again.
+            Some(unsafe { self.data[idx].as_ptr().read() })
+        }
+    }
+
+    /// Returns a reference to the oldest element in the buffer, or `None` if it is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the oldest element in the buffer, or `None` if it is
+    /// empty.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the newest element in the buffer, or `None` if it is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|idx| self.get(idx))
+    }
+
+    /// Returns a mutable reference to the newest element in the buffer, or `None` if it is
+    /// empty.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.len
+            .checked_sub(1)
+            .and_then(move |idx| self.get_mut(idx))
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// Index `0` refers to the oldest element, matching the behavior of [`Index`].
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            Some(&self[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if `index` is out of
+    /// bounds.
+    ///
+    /// Index `0` refers to the oldest element, matching the behavior of [`IndexMut`].
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            Some(&mut self[index])
+        } else {
+            None
         }
     }
 
@@ -204,8 +291,144 @@ impl<T, const SIZE: usize> CircBuf<T, SIZE> {
     }
 
     /// Returns an iterator over the elements in the buffer.
-    pub fn iter(&self) -> Iter<T, SIZE> {
-        Iter { buf: self, idx: 0 }
+    pub fn iter(&self) -> Iter<'_, T, SIZE> {
+        Iter {
+            buf: self,
+            idx: 0,
+            end: self.len,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element in the buffer.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, SIZE> {
+        IterMut {
+            data: self.data.as_mut_ptr(),
+            start: self.start,
+            idx: 0,
+            end: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the valid data as two slices, in oldest-to-newest order.
+    ///
+    /// The first slice holds the elements starting at the internal `start` index up to the
+    /// end of the backing array; the second slice holds whatever wrapped around to the
+    /// beginning. If the data does not wrap, the second slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBuf;
+    /// let mut buf: CircBuf<_, 4> = CircBuf::new();
+    /// for i in 0..6 {
+    ///     buf.push(i);
+    /// }
+    /// let (head, tail) = buf.as_slices();
+    /// assert_eq!(head, &[2, 3]);
+    /// assert_eq!(tail, &[4, 5]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let head_len = self.len.min(SIZE - self.start);
+        let tail_len = self.len - head_len;
+        // SAFETY:
+        // `head` covers the `head_len` initialized slots starting at `start`, and `tail`
+        // covers the `tail_len` initialized slots wrapped around to the beginning of `data`.
+        // Together they are exactly the `len` valid elements, and the two regions never
+        // overlap since `tail_len <= start`.
+        unsafe {
+            let head =
+                std::slice::from_raw_parts(self.data.as_ptr().add(self.start).cast(), head_len);
+            let tail = std::slice::from_raw_parts(self.data.as_ptr().cast(), tail_len);
+            (head, tail)
+        }
+    }
+
+    /// Returns the valid data as two mutable slices, in oldest-to-newest order.
+    ///
+    /// See [`as_slices`] for details on how the data is split.
+    ///
+    /// [`as_slices`]: CircBuf::as_slices
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let head_len = self.len.min(SIZE - self.start);
+        let tail_len = self.len - head_len;
+        let base = self.data.as_mut_ptr();
+        // SAFETY: see `as_slices`; the two regions are disjoint, so they may be borrowed as
+        // mutable slices at the same time.
+        unsafe {
+            let head = std::slice::from_raw_parts_mut(base.add(self.start).cast(), head_len);
+            let tail = std::slice::from_raw_parts_mut(base.cast(), tail_len);
+            (head, tail)
+        }
+    }
+
+    /// Rearranges the internal storage so that the valid data is stored contiguously,
+    /// starting at index `0`, and returns it as a single slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBuf;
+    /// let mut buf: CircBuf<_, 4> = CircBuf::new();
+    /// for i in 0..6 {
+    ///     buf.push(i);
+    /// }
+    /// assert_eq!(buf.make_contiguous(), &[2, 3, 4, 5]);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.start != 0 {
+            self.data.rotate_left(self.start);
+            self.start = 0;
+        }
+        // SAFETY: after the rotation above, the `len` valid elements occupy `data[0..len]`.
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), self.len) }
+    }
+}
+
+impl<T, const SIZE: usize> Drop for CircBuf<T, SIZE> {
+    fn drop(&mut self) {
+        raw::drop_valid(&mut self.data, self.start, self.len);
+    }
+}
+
+impl<T: Clone, const SIZE: usize> Clone for CircBuf<T, SIZE> {
+    fn clone(&self) -> Self {
+        let mut cloned = Self::new();
+        cloned.extend(self.iter().cloned());
+        cloned
+    }
+}
+
+impl<T: PartialEq, const SIZE: usize> PartialEq for CircBuf<T, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const SIZE: usize> Eq for CircBuf<T, SIZE> {}
+
+impl<T: Hash, const SIZE: usize> Hash for CircBuf<T, SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for elem in self.iter() {
+            elem.hash(state);
+        }
+    }
+}
+
+impl<T, const SIZE: usize> Extend<T> for CircBuf<T, SIZE> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+impl<T, const SIZE: usize> FromIterator<T> for CircBuf<T, SIZE> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
     }
 }
 
@@ -264,15 +487,17 @@ impl<T, const SIZE: usize> IndexMut<usize> for CircBuf<T, SIZE> {
 pub struct Iter<'a, T, const SIZE: usize> {
     /// Reference to the circular buffer to iterate over.
     buf: &'a CircBuf<T, SIZE>,
-    /// Index of the next value to return from iterator.
+    /// Index of the next value to return from the front of the iterator.
     idx: usize,
+    /// Index one past the next value to return from the back of the iterator.
+    end: usize,
 }
 
 impl<'a, T, const SIZE: usize> Iterator for Iter<'a, T, SIZE> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx < self.buf.len() {
+        if self.idx < self.end {
             let elem = &self.buf[self.idx];
             self.idx += 1;
             Some(elem)
@@ -281,11 +506,284 @@ impl<'a, T, const SIZE: usize> Iterator for Iter<'a, T, SIZE> {
         }
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const SIZE: usize> DoubleEndedIterator for Iter<'a, T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx < self.end {
+            self.end -= 1;
+            Some(&self.buf[self.end])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, const SIZE: usize> ExactSizeIterator for Iter<'a, T, SIZE> {}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a CircBuf<T, SIZE> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, SIZE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutable iterator over elements of a circular buffer.
+/// Created from a `CircBuf` using [`iter_mut`].
+///
+/// [`iter_mut`]: CircBuf::iter_mut
+pub struct IterMut<'a, T, const SIZE: usize> {
+    /// Pointer to the start of the circular buffer's backing storage.
+    data: *mut MaybeUninit<T>,
+    /// Start of the valid data in the buffer, copied from the `CircBuf` being iterated.
+    start: usize,
+    /// Index of the next value to return from the front of the iterator.
+    idx: usize,
+    /// Index one past the next value to return from the back of the iterator.
+    end: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, const SIZE: usize> IterMut<'a, T, SIZE> {
+    /// # Safety
+    /// `logical_idx` must be less than the buffer's `len` at the time `self` was created.
+    unsafe fn get_mut(&mut self, logical_idx: usize) -> &'a mut T {
+        let physical = (self.start + logical_idx) % SIZE;
+        // SAFETY:
+        // `physical` addresses one of the `len` initialized elements of the buffer, and
+        // each logical index is handed out to the caller at most once, so this reference
+        // does not alias any other reference produced by this iterator.
+        unsafe { &mut *(*self.data.add(physical)).as_mut_ptr() }
+    }
+}
+
+impl<'a, T, const SIZE: usize> Iterator for IterMut<'a, T, SIZE> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < self.end {
+            // SAFETY: `self.idx` is less than `self.end`, which is at most the buffer's `len`.
+            let elem = unsafe { self.get_mut(self.idx) };
+            self.idx += 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, const SIZE: usize> DoubleEndedIterator for IterMut<'a, T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.idx < self.end {
+            self.end -= 1;
+            // SAFETY: `self.end` is less than the buffer's `len`.
+            Some(unsafe { self.get_mut(self.end) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, const SIZE: usize> ExactSizeIterator for IterMut<'a, T, SIZE> {}
+
+impl<'a, T, const SIZE: usize> IntoIterator for &'a mut CircBuf<T, SIZE> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, SIZE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Owning iterator over elements of a circular buffer, moving each element out in
+/// oldest-to-newest order.
+///
+/// Created from a `CircBuf` via its `IntoIterator` implementation.
+pub struct IntoIter<T, const SIZE: usize> {
+    buf: CircBuf<T, SIZE>,
+}
+
+impl<T, const SIZE: usize> Iterator for IntoIter<T, SIZE> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buf.pop()
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.buf.len(), Some(self.buf.len()))
     }
 }
 
+impl<T, const SIZE: usize> DoubleEndedIterator for IntoIter<T, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.buf.pop_back()
+    }
+}
+
+impl<T, const SIZE: usize> ExactSizeIterator for IntoIter<T, SIZE> {}
+
+impl<T, const SIZE: usize> IntoIterator for CircBuf<T, SIZE> {
+    type Item = T;
+    type IntoIter = IntoIter<T, SIZE>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { buf: self }
+    }
+}
+
+/// A common interface shared by the const-generic [`CircBuf`] and the heap-allocated
+/// [`CircBufVec`], so callers can write code that is generic over which backing storage
+/// is used.
+pub trait CircularBuffer<T> {
+    /// Add a new element to the buffer, overwriting the oldest element if the buffer is full.
+    fn push(&mut self, elem: T);
+
+    /// Remove the oldest element from the buffer and return it, or `None` if it is empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Returns the number of elements currently stored in the buffer.
+    fn len(&self) -> usize;
+
+    /// Returns the maximum number of elements the buffer can hold.
+    fn capacity(&self) -> usize;
+
+    /// Returns `true` if the buffer contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the buffer is full and the next `push` will overwrite data.
+    fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns an iterator over the elements in the buffer, oldest first.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+}
+
+impl<T, const SIZE: usize> CircularBuffer<T> for CircBuf<T, SIZE> {
+    fn push(&mut self, elem: T) {
+        CircBuf::push(self, elem)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        CircBuf::pop(self)
+    }
+
+    fn len(&self) -> usize {
+        CircBuf::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        SIZE
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        CircBuf::iter(self)
+    }
+}
+
+impl<const SIZE: usize> CircBuf<u8, SIZE> {
+    /// Reads bytes from `reader` directly into the buffer's free space, without overwriting
+    /// any valid data, and returns how many bytes were read.
+    ///
+    /// `reader.read` is called at most once for each contiguous free region (so at most
+    /// twice, when the free space wraps around the end of the storage); a read that does
+    /// not fill the first region ends the call.
+    ///
+    /// Unlike the `Write` implementation's `write`, this never overwrites the oldest
+    /// element: it stops once the buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use circbuf::CircBuf;
+    /// let mut buf: CircBuf<u8, 8> = CircBuf::new();
+    /// let mut data: &[u8] = b"hello";
+    /// let n = buf.fill_from(&mut data).unwrap();
+    /// assert_eq!(n, 5);
+    /// assert_eq!(buf.make_contiguous(), b"hello");
+    /// ```
+    pub fn fill_from<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<usize> {
+        let free = SIZE - self.len;
+        if free == 0 {
+            return Ok(0);
+        }
+
+        let write_idx = (self.start + self.len) % SIZE;
+        let first_len = free.min(SIZE - write_idx);
+        // SAFETY:
+        // `u8` has no invalid bit patterns, so the free, possibly-uninitialized region of
+        // `data` starting at `write_idx` may be treated as a plain `&mut [u8]`; `reader.read`
+        // only ever writes into the slice it is given.
+        let first = unsafe {
+            std::slice::from_raw_parts_mut(
+                self.data.as_mut_ptr().add(write_idx).cast::<u8>(),
+                first_len,
+            )
+        };
+        let mut total = reader.read(first)?;
+        self.len += total;
+
+        if total == first_len {
+            let second_len = free - first_len;
+            if second_len > 0 {
+                // SAFETY: same reasoning as `first`, for the free region wrapped around to
+                // the beginning of `data`.
+                let second = unsafe {
+                    std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<u8>(), second_len)
+                };
+                let n = reader.read(second)?;
+                total += n;
+                self.len += n;
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+impl<const SIZE: usize> std::io::Write for CircBuf<u8, SIZE> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for &byte in buf {
+            self.push(byte);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<const SIZE: usize> std::io::Read for CircBuf<u8, SIZE> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.len());
+        for slot in buf.iter_mut().take(n) {
+            // SAFETY: `n` is at most `self.len()`, so each `pop` below is guaranteed `Some`.
+            *slot = self.pop().unwrap();
+        }
+        Ok(n)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,4 +837,412 @@ mod tests {
             vec![1, 2, 3, 4, 5]
         );
     }
+
+    /// A value whose drop increments a shared counter, used to verify that `CircBuf` runs
+    /// destructors the right number of times.
+    struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        for val in buf.iter_mut() {
+            *val *= 10;
+        }
+        assert_eq!(
+            buf.iter().copied().collect::<Vec<i32>>(),
+            vec![20, 30, 40, 50]
+        );
+    }
+
+    #[test]
+    fn test_iter_double_ended() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        let mut iter = buf.iter();
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_exact_size() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        assert_eq!(buf.iter().len(), 4);
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        assert_eq!(buf.into_iter().collect::<Vec<i32>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        let mut iter = buf.into_iter();
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next_back(), Some(5));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_into_iter_drops_remaining() {
+        let count = std::cell::Cell::new(0);
+        {
+            let mut buf: CircBuf<_, 4> = CircBuf::new();
+            for _ in 0..4 {
+                buf.push(DropCounter(&count));
+            }
+            let mut iter = buf.into_iter();
+            iter.next();
+            assert_eq!(count.get(), 1);
+        }
+        assert_eq!(count.get(), 4);
+    }
+
+    #[test]
+    fn test_for_loop_over_ref_and_mut_ref() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..4 {
+            buf.push(i);
+        }
+        for val in &mut buf {
+            *val += 1;
+        }
+        let mut collected = Vec::new();
+        for val in &buf {
+            collected.push(*val);
+        }
+        assert_eq!(collected, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        let cloned = buf.clone();
+        assert_eq!(
+            cloned.iter().copied().collect::<Vec<i32>>(),
+            buf.iter().copied().collect::<Vec<i32>>()
+        );
+    }
+
+    #[test]
+    fn test_eq_ignores_internal_start_offset() {
+        // `a` is rotated via push/pop so its `start` differs from `b`'s, but both hold the
+        // same logical sequence and must still compare equal.
+        let mut a: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            a.push(i);
+        }
+        let mut b: CircBuf<_, 4> = CircBuf::new();
+        for i in 2..6 {
+            b.push(i);
+        }
+
+        assert_eq!(a, b);
+
+        b.push(99);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hash_ignores_internal_start_offset() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(val: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            val.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut a: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            a.push(i);
+        }
+        let mut b: CircBuf<_, 4> = CircBuf::new();
+        for i in 2..6 {
+            b.push(i);
+        }
+
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn test_from_iterator_and_extend() {
+        let buf: CircBuf<_, 4> = (0..6).collect();
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![2, 3, 4, 5]);
+
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        buf.push(1);
+        buf.extend(2..6);
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        buf.push(2);
+        buf.push(3);
+        buf.push_front(1);
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_push_front_overwrites_newest_when_full() {
+        let mut buf: CircBuf<_, 3> = CircBuf::new();
+        for i in 1..=3 {
+            buf.push(i);
+        }
+        // Buffer is full ([1, 2, 3]); pushing to the front overwrites the newest element.
+        buf.push_front(0);
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        buf.push(1);
+        buf.push(2);
+        buf.push(3);
+        assert_eq!(buf.pop_back(), Some(3));
+        assert_eq!(buf.pop_back(), Some(2));
+        assert_eq!(buf.len(), 1);
+        assert_eq!(buf.pop_back(), Some(1));
+        assert_eq!(buf.pop_back(), None);
+    }
+
+    #[test]
+    fn test_front_and_back() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        assert_eq!(buf.front(), None);
+        assert_eq!(buf.back(), None);
+        for i in 0..6 {
+            buf.push(i);
+        }
+        assert_eq!(buf.front(), Some(&2));
+        assert_eq!(buf.back(), Some(&5));
+
+        *buf.front_mut().unwrap() += 100;
+        *buf.back_mut().unwrap() += 100;
+        assert_eq!(
+            buf.iter().copied().collect::<Vec<i32>>(),
+            vec![102, 3, 4, 105]
+        );
+    }
+
+    #[test]
+    fn test_get_and_get_mut() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        assert_eq!(buf.get(0), Some(&2));
+        assert_eq!(buf.get(3), Some(&5));
+        assert_eq!(buf.get(4), None);
+
+        *buf.get_mut(1).unwrap() *= 10;
+        assert_eq!(buf.get(1), Some(&30));
+    }
+
+    #[test]
+    fn test_sliding_window_from_both_ends() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        buf.push(2);
+        buf.push(3);
+        buf.push_front(1);
+        buf.push(4);
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+        assert_eq!(buf.pop_back(), Some(4));
+        assert_eq!(buf.pop(), Some(1));
+        assert_eq!(buf.iter().copied().collect::<Vec<i32>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_write_pushes_bytes() {
+        use std::io::Write;
+        let mut buf: CircBuf<u8, 4> = CircBuf::new();
+        let n = buf.write(b"hello").unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(buf.make_contiguous(), b"ello");
+    }
+
+    #[test]
+    fn test_read_pops_bytes() {
+        use std::io::Read;
+        let mut buf: CircBuf<u8, 8> = CircBuf::new();
+        for &byte in b"hello" {
+            buf.push(byte);
+        }
+        let mut out = [0u8; 3];
+        let n = buf.read(&mut out).unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(&out, b"hel");
+        assert_eq!(buf.len(), 2);
+    }
+
+    #[test]
+    fn test_fill_from_contiguous() {
+        let mut buf: CircBuf<u8, 8> = CircBuf::new();
+        let mut data: &[u8] = b"hello";
+        let n = buf.fill_from(&mut data).unwrap();
+        assert_eq!(n, 5);
+        assert_eq!(buf.make_contiguous(), b"hello");
+    }
+
+    #[test]
+    fn test_fill_from_wrapping_free_region() {
+        let mut buf: CircBuf<u8, 8> = CircBuf::new();
+        for &byte in b"abcdef" {
+            buf.push(byte);
+        }
+        buf.pop();
+        buf.pop();
+        buf.pop();
+        buf.pop();
+        // Free space now wraps: 2 free slots at the tail (indices 6 and 7), plus 4 at the
+        // front (indices 0 to 3).
+        let mut data: &[u8] = b"XYZ";
+        let n = buf.fill_from(&mut data).unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(buf.make_contiguous(), b"efXYZ");
+    }
+
+    #[test]
+    fn test_fill_from_stops_when_full() {
+        let mut buf: CircBuf<u8, 4> = CircBuf::new();
+        for &byte in b"ab" {
+            buf.push(byte);
+        }
+        let mut data: &[u8] = b"cdef";
+        let n = buf.fill_from(&mut data).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(buf.make_contiguous(), b"abcd");
+    }
+
+    #[test]
+    fn test_drop_on_buffer_drop() {
+        let count = std::cell::Cell::new(0);
+        {
+            let mut buf: CircBuf<_, 4> = CircBuf::new();
+            for _ in 0..3 {
+                buf.push(DropCounter(&count));
+            }
+            assert_eq!(count.get(), 0);
+        }
+        assert_eq!(count.get(), 3);
+    }
+
+    #[test]
+    fn test_drop_on_wrapping_overwrite() {
+        let count = std::cell::Cell::new(0);
+        {
+            let mut buf: CircBuf<_, 4> = CircBuf::new();
+            for _ in 0..4 {
+                buf.push(DropCounter(&count));
+            }
+            assert_eq!(count.get(), 0);
+
+            // Buffer is full, so each of these pushes must drop the element it overwrites.
+            buf.push(DropCounter(&count));
+            assert_eq!(count.get(), 1);
+            buf.push(DropCounter(&count));
+            assert_eq!(count.get(), 2);
+        }
+        assert_eq!(count.get(), 6);
+    }
+
+    #[test]
+    fn test_as_slices_contiguous() {
+        let mut buf: CircBuf<_, 8> = CircBuf::new();
+        for i in 0..5 {
+            buf.push(i);
+        }
+        let (head, tail) = buf.as_slices();
+        assert_eq!(head, &[0, 1, 2, 3, 4]);
+        assert_eq!(tail, &[] as &[i32]);
+    }
+
+    #[test]
+    fn test_as_slices_wrapping() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        let (head, tail) = buf.as_slices();
+        assert_eq!(head, &[2, 3]);
+        assert_eq!(tail, &[4, 5]);
+    }
+
+    #[test]
+    fn test_as_mut_slices_wrapping() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        {
+            let (head, tail) = buf.as_mut_slices();
+            for val in head.iter_mut().chain(tail.iter_mut()) {
+                *val *= 10;
+            }
+        }
+        assert_eq!(
+            buf.iter().copied().collect::<Vec<i32>>(),
+            vec![20, 30, 40, 50]
+        );
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut buf: CircBuf<_, 4> = CircBuf::new();
+        for i in 0..6 {
+            buf.push(i);
+        }
+        assert_eq!(buf.make_contiguous(), &[2, 3, 4, 5]);
+        let (head, tail) = buf.as_slices();
+        assert_eq!(head, &[2, 3, 4, 5]);
+        assert_eq!(tail, &[] as &[i32]);
+    }
+
+    #[test]
+    fn test_drop_on_partial_fill() {
+        let count = std::cell::Cell::new(0);
+        {
+            let mut buf: CircBuf<_, 8> = CircBuf::new();
+            for _ in 0..6 {
+                buf.push(DropCounter(&count));
+            }
+            assert!(buf.pop().is_some());
+            assert_eq!(count.get(), 1);
+        }
+        // 6 pushed, 1 popped (and dropped by the caller above), 5 remain for `Drop` to clean up.
+        assert_eq!(count.get(), 6);
+    }
 }
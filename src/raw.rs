@@ -0,0 +1,81 @@
+//! Ring-buffer index arithmetic shared by [`CircBuf`](crate::CircBuf) and
+//! [`CircBufVec`](crate::CircBufVec), so the two backing stores don't carry independent,
+//! hand-synced copies of the same unsafe code.
+
+use std::{mem::MaybeUninit, ptr};
+
+/// Adds `elem` to the logical back of a ring buffer backed by `data`, overwriting and
+/// dropping the oldest element when the buffer is full.
+///
+/// `start`/`len` are the usual ring buffer bookkeeping fields; `data`'s length is the
+/// buffer's capacity.
+///
+/// # Panics
+///
+/// Panics if `data` is empty, since a zero-capacity ring buffer has no valid index
+/// arithmetic.
+pub(crate) fn push_back<T>(
+    data: &mut [MaybeUninit<T>],
+    start: &mut usize,
+    len: &mut usize,
+    elem: T,
+) {
+    let capacity = data.len();
+    assert!(capacity > 0, "capacity must be non-zero");
+    let write_idx = (*start + *len) % capacity;
+
+    if *len == capacity {
+        // SAFETY:
+        // The buffer is full, so every slot holds an initialized value, including
+        // `write_idx`. It is about to be overwritten below, so the old value must be
+        // dropped here or it would otherwise leak.
+        unsafe { ptr::drop_in_place(data[write_idx].as_mut_ptr()) };
+        *start = (*start + 1) % capacity;
+    } else {
+        *len += 1;
+    }
+
+    data[write_idx] = MaybeUninit::new(elem);
+}
+
+/// Removes and returns the logical front element of a ring buffer backed by `data`, or
+/// `None` if `len` is `0`.
+pub(crate) fn pop_front<T>(
+    data: &[MaybeUninit<T>],
+    start: &mut usize,
+    len: &mut usize,
+) -> Option<T> {
+    if *len == 0 {
+        None
+    } else {
+        let capacity = data.len();
+        // SAFETY:
+        // * Length is greater than zero so the buffer contains an initialized element *somewhere*.
+        // * `start` always marks the oldest of the `len` valid elements.
+        // This means `start` must point to a properly initialized value and the following
+        // operation is safe.
+        //
+        // `ptr::read` does not drop the underlying value, but this is intended:
+        // Ownership of the value is transfered to the caller, and the remnents of the value
+        // in the array will be overwritten by other writes later.
+        let elem = unsafe { data[*start].as_ptr().read() };
+
+        *start = (*start + 1) % capacity;
+        *len -= 1;
+
+        Some(elem)
+    }
+}
+
+/// Drops every currently-valid element of a ring buffer backed by `data`. Intended to be
+/// called from the container's own `Drop` impl.
+pub(crate) fn drop_valid<T>(data: &mut [MaybeUninit<T>], start: usize, len: usize) {
+    let capacity = data.len();
+    for i in 0..len {
+        let index = (start + i) % capacity;
+        // SAFETY:
+        // `index` is one of the `len` valid slots starting at `start`, so it is
+        // guaranteed to hold an initialized value that has not been dropped yet.
+        unsafe { ptr::drop_in_place(data[index].as_mut_ptr()) };
+    }
+}